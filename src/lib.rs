@@ -2,12 +2,15 @@
 //!
 //! Glanceable source code for prototypes seeking brevity with transparency.
 
+use std::sync::{ Arc, Barrier, Mutex, Condvar, OnceLock };
+use std::sync::atomic::{ AtomicUsize, Ordering };
 use std::thread;
 use std::time::Instant;
 
 use crossbeam::channel::{
     Sender,
     Receiver,
+    bounded,
     unbounded
 };
 use uuid::Uuid;
@@ -19,6 +22,50 @@ enum Message {
     Terminate,
 }
 
+/// Tracks queued and active job counts so `ThreadPool::join` can wait for
+/// outstanding work to drain.
+#[derive(Default)]
+struct Counters {
+    queued: AtomicUsize,
+    active: AtomicUsize,
+    lock: Mutex<()>,
+    condvar: Condvar
+}
+
+impl Counters {
+    fn job_queued(&self) {
+        self.queued.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn job_started(&self) {
+        // count the job active before it leaves the queue, so queued + active
+        // never reads zero while the job is starting
+        self.active.fetch_add(1, Ordering::SeqCst);
+        self.queued.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    fn job_finished(&self) {
+        self.active.fetch_sub(1, Ordering::SeqCst);
+        self.notify_if_idle();
+    }
+
+    fn job_dropped(&self) {
+        self.queued.fetch_sub(1, Ordering::SeqCst);
+        self.notify_if_idle();
+    }
+
+    fn notify_if_idle(&self) {
+        if self.queued.load(Ordering::SeqCst) + self.active.load(Ordering::SeqCst) == 0 {
+            let _guard = self.lock.lock().unwrap();
+            self.condvar.notify_all();
+        }
+    }
+
+    fn is_idle(&self) -> bool {
+        self.queued.load(Ordering::SeqCst) + self.active.load(Ordering::SeqCst) == 0
+    }
+}
+
 #[allow(dead_code)]
 struct Worker {
     id: Uuid,
@@ -26,34 +73,224 @@ struct Worker {
     created: Instant
 }
 
-impl Worker {
-    fn new(receiver: Receiver<Message>) -> Worker {
-        let thread = thread::spawn(move || loop {
-            let message = receiver.recv().unwrap();
+/// Guards a worker's loop so a job panic does not silently shrink the pool.
+///
+/// If the thread is unwinding when the sentinel drops, a replacement worker
+/// is spawned on the same channel and swapped into `workers` in place of the
+/// dying worker's own entry, so `ThreadPool::capacity()` and `resize()` stay
+/// in sync with the live thread count. A clean `Message::Terminate` exit
+/// disarms the sentinel first, so normal shutdown never respawns.
+struct Sentinel {
+    id: Uuid,
+    receiver: Receiver<Message>,
+    done: Sender<Uuid>,
+    counters: Arc<Counters>,
+    name: Option<String>,
+    workers: Arc<Mutex<Vec<Worker>>>,
+    armed: bool
+}
+
+impl Sentinel {
+    fn new(id: Uuid, receiver: Receiver<Message>, done: Sender<Uuid>, counters: Arc<Counters>, name: Option<String>, workers: Arc<Mutex<Vec<Worker>>>) -> Sentinel {
+        Sentinel { id, receiver, done, counters, name, workers, armed: true }
+    }
+
+    fn disarm(&mut self) {
+        self.armed = false;
+    }
+}
 
-            match message {
-                Message::NewJob(job) => {
-                    job();
+impl Drop for Sentinel {
+    fn drop(&mut self) {
+        if self.armed && thread::panicking() {
+            // the panicked job never reached its matching job_finished()
+            self.counters.job_finished();
+
+            // reserve the replacement's id in this worker's own slot before
+            // spawning it, so the new thread's id is already registered if
+            // it dequeues and panics on another job before we get back here;
+            // otherwise its own Sentinel::drop would find nothing to match
+            // and register a second, orphaned entry alongside this one.
+            // The lock is held until the replacement's handle is stored, so
+            // a ThreadPool drop never sees the new id without its handle.
+            let id = Uuid::new_v4();
+            let mut workers = self.workers.lock().unwrap();
+            match workers.iter_mut().find(|worker| worker.id == self.id) {
+                Some(worker) => {
+                    worker.id = id;
+                    worker.thread = None;
+                    worker.created = Instant::now();
                 }
-                Message::Terminate => {
-                    break;
+                // defensive only: every worker's id is reserved in
+                // `workers` before it can start, so self.id should
+                // always be found above
+                None => workers.push(Worker { id, thread: None, created: Instant::now() })
+            }
+
+            let thread = spawn_worker(id, self.receiver.clone(), self.done.clone(), self.counters.clone(), self.name.clone(), self.workers.clone());
+
+            if let Some(worker) = workers.iter_mut().find(|worker| worker.id == id) {
+                worker.thread = Some(thread);
+            }
+        }
+    }
+}
+
+fn spawn_worker(id: Uuid, receiver: Receiver<Message>, done: Sender<Uuid>, counters: Arc<Counters>, name: Option<String>, workers: Arc<Mutex<Vec<Worker>>>) -> thread::JoinHandle<()> {
+    let body = {
+        let name = name.clone();
+
+        move || {
+            let mut sentinel = Sentinel::new(id, receiver.clone(), done.clone(), counters.clone(), name, workers);
+
+            loop {
+                let message = receiver.recv().unwrap();
+
+                match message {
+                    Message::NewJob(job) => {
+                        counters.job_started();
+                        job();
+                        counters.job_finished();
+                    }
+                    Message::Terminate => {
+                        sentinel.disarm();
+                        let _ = done.send(id);
+                        break;
+                    }
                 }
             }
-        });
+        }
+    };
+
+    match name {
+        Some(name) => thread::Builder::new().name(name).spawn(body).unwrap(),
+        None => thread::spawn(body),
+    }
+}
+
+/// Reserves a new worker's slot in `workers` before spawning its thread, so
+/// the thread can never dequeue a job (and potentially panic-respawn via its
+/// own `Sentinel`) before its id is already present in the registry.
+fn register_worker(workers: &Arc<Mutex<Vec<Worker>>>, receiver: Receiver<Message>, done: Sender<Uuid>, counters: Arc<Counters>, name: Option<String>) {
+    let id = Uuid::new_v4();
+
+    workers.lock().unwrap().push(Worker { id, thread: None, created: Instant::now() });
+
+    let thread = spawn_worker(id, receiver, done, counters, name, workers.clone());
+
+    if let Some(worker) = workers.lock().unwrap().iter_mut().find(|worker| worker.id == id) {
+        worker.thread = Some(thread);
+    }
+}
+
+/// Policy applied once `execute`/`try_execute` would overflow a bounded job
+/// queue.
+pub enum OverflowPolicy {
+    /// Block the caller until the queue has room.
+    Block,
+    /// Discard the job and report it back to the caller instead of blocking.
+    DropNewest
+}
+
+/// Returned by [`ThreadPool::try_execute`] when the job queue is full and
+/// the pool's [`OverflowPolicy`] is [`OverflowPolicy::DropNewest`].
+#[derive(Debug)]
+pub struct QueueFullError;
+
+/// Builds a [`ThreadPool`] with a configurable number of threads, worker
+/// thread name, queue capacity, and overflow policy.
+pub struct ThreadPoolBuilder {
+    num_threads: usize,
+    thread_name: Option<String>,
+    queue_capacity: Option<usize>,
+    overflow: OverflowPolicy
+}
+
+impl ThreadPoolBuilder {
+    /// Start a builder with `ThreadPool::new`'s defaults: one thread, an
+    /// unnamed worker, an unbounded queue, and `OverflowPolicy::Block`.
+    pub fn new() -> ThreadPoolBuilder {
+        ThreadPoolBuilder {
+            num_threads: 1,
+            thread_name: None,
+            queue_capacity: None,
+            overflow: OverflowPolicy::Block
+        }
+    }
+
+    /// Number of worker threads in the built pool.
+    pub fn num_threads(mut self, num_threads: usize) -> ThreadPoolBuilder {
+        self.num_threads = num_threads;
+        self
+    }
+
+    /// Name given to every worker thread, passed to `thread::Builder`.
+    pub fn thread_name(mut self, name: String) -> ThreadPoolBuilder {
+        self.thread_name = Some(name);
+        self
+    }
+
+    /// Caps the job queue at `capacity`, or leaves it unbounded when `None`.
+    pub fn queue_capacity(mut self, capacity: Option<usize>) -> ThreadPoolBuilder {
+        self.queue_capacity = capacity;
+        self
+    }
+
+    /// Policy applied once the job queue reaches `queue_capacity`.
+    pub fn overflow_policy(mut self, policy: OverflowPolicy) -> ThreadPoolBuilder {
+        self.overflow = policy;
+        self
+    }
+
+    /// Build the configured `ThreadPool`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_threads` is zero.
+    pub fn build(self) -> ThreadPool {
+        assert!(self.num_threads > 0);
+
+        let (sender, receiver) = match self.queue_capacity {
+            Some(capacity) => bounded(capacity),
+            None => unbounded()
+        };
+        let (done_sender, done_receiver) = unbounded();
+        let counters = Arc::new(Counters::default());
+
+        let workers = Arc::new(Mutex::new(Vec::with_capacity(self.num_threads)));
+        for _ in 0..self.num_threads {
+            register_worker(&workers, receiver.clone(), done_sender.clone(), counters.clone(), self.thread_name.clone());
+        }
 
-        Worker {
-            id: Uuid::new_v4(),
-            thread: Some(thread),
-            created: Instant::now()
+        ThreadPool {
+            workers,
+            sender,
+            receiver,
+            done_sender,
+            done_receiver,
+            counters,
+            thread_name: self.thread_name,
+            overflow: self.overflow
         }
     }
 }
 
+impl Default for ThreadPoolBuilder {
+    fn default() -> ThreadPoolBuilder {
+        ThreadPoolBuilder::new()
+    }
+}
+
 /// Thread pool of workers awaiting execution orders.
 pub struct ThreadPool {
-    workers: Vec<Worker>,
+    workers: Arc<Mutex<Vec<Worker>>>,
     sender: Sender<Message>,
-    receiver: Receiver<Message>
+    receiver: Receiver<Message>,
+    done_sender: Sender<Uuid>,
+    done_receiver: Receiver<Uuid>,
+    counters: Arc<Counters>,
+    thread_name: Option<String>,
+    overflow: OverflowPolicy
 }
 
 impl ThreadPool {
@@ -90,63 +327,303 @@ impl ThreadPool {
     /// assert_eq!(has_executed.load(Ordering::SeqCst), true);
     /// ```
     pub fn new(capacity: usize) -> ThreadPool {
-        assert!(capacity > 0);
-
-        // create crossbeam crate channel of unbounded capacity
-        let (sender, receiver) = unbounded();
-
-        let mut workers = Vec::with_capacity(capacity);
-        for _ in 0..capacity {
-            workers.push(Worker::new(receiver.clone()));
-        }
-
-        ThreadPool { workers, sender, receiver }
+        ThreadPoolBuilder::new()
+            .num_threads(capacity)
+            .build()
     }
 
     /// Capacity of thread pool (number of workers).
     pub fn capacity(&self) -> usize {
-        self.workers.len()
+        self.workers.lock().unwrap().len()
     }
 
-    /// Resize thread pool to new capacity
+    /// Resize thread pool to new capacity.
+    ///
+    /// Growing spawns `capacity - self.capacity()` additional workers onto the
+    /// same job channel. Shrinking pushes enough `Message::Terminate` onto the
+    /// channel to retire `self.capacity() - capacity` workers, then waits for
+    /// each retiring worker to report back before joining its handle, since
+    /// any idle worker on the shared channel may be the one to consume a
+    /// given `Terminate`.
     ///
     /// # Panics
     ///
     /// The `resize` function will panic if the capacity is zero.
-    pub fn resize(&self, capacity: usize) {
+    pub fn resize(&mut self, capacity: usize) {
         assert!(capacity > 0);
 
-        // fixme
+        let current = self.workers.lock().unwrap().len();
+
+        if capacity > current {
+            for _ in 0..(capacity - current) {
+                register_worker(&self.workers, self.receiver.clone(), self.done_sender.clone(), self.counters.clone(), self.thread_name.clone());
+            }
+        } else if capacity < current {
+            let retiring = current - capacity;
+
+            for _ in 0..retiring {
+                self.sender.send(Message::Terminate).unwrap();
+            }
+
+            for _ in 0..retiring {
+                let id = self.done_receiver.recv().unwrap();
+
+                let mut workers = self.workers.lock().unwrap();
+                if let Some(index) = workers.iter().position(|worker| worker.id == id) {
+                    let mut worker = workers.remove(index);
+                    drop(workers);
+
+                    if let Some(thread) = worker.thread.take() {
+                        thread.join().unwrap();
+                    }
+                }
+            }
+        }
     }
 
 
     /// Execute function/closure using worker from thread pool.
+    ///
+    /// Under `OverflowPolicy::Block` (the default) this blocks the caller
+    /// once the queue is full; under `OverflowPolicy::DropNewest` the job is
+    /// silently discarded instead. Use [`ThreadPool::try_execute`] to be
+    /// told when a job is dropped.
     pub fn execute<F>(&self, f: F)
         where
             F: FnOnce() + Send + 'static,
+    {
+        match self.overflow {
+            OverflowPolicy::Block => self.send_job(Box::new(f)),
+            OverflowPolicy::DropNewest => {
+                let _ = self.try_execute(f);
+            }
+        }
+    }
+
+    /// Execute function/closure using a worker from the thread pool,
+    /// reporting back instead of blocking if the queue is full.
+    ///
+    /// This only differs from `execute` under `OverflowPolicy::DropNewest`;
+    /// under `OverflowPolicy::Block` it always succeeds (blocking if the
+    /// queue is full).
+    pub fn try_execute<F>(&self, f: F) -> Result<(), QueueFullError>
+        where
+            F: FnOnce() + Send + 'static,
     {
         let job = Box::new(f);
 
+        self.counters.job_queued();
+
+        match self.overflow {
+            OverflowPolicy::Block => {
+                self.sender.send(Message::NewJob(job)).unwrap();
+                Ok(())
+            }
+            OverflowPolicy::DropNewest => {
+                match self.sender.try_send(Message::NewJob(job)) {
+                    Ok(()) => Ok(()),
+                    Err(_) => {
+                        self.counters.job_dropped();
+                        Err(QueueFullError)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Number of jobs currently executing on a worker.
+    pub fn active_count(&self) -> usize {
+        self.counters.active.load(Ordering::SeqCst)
+    }
+
+    /// Number of jobs submitted but not yet picked up by a worker.
+    pub fn queued_count(&self) -> usize {
+        self.counters.queued.load(Ordering::SeqCst)
+    }
+
+    /// Block until every queued and active job has finished.
+    ///
+    /// Tolerates spurious wakeups by re-checking `active_count() +
+    /// queued_count()` under the lock before returning.
+    pub fn join(&self) {
+        let guard = self.counters.lock.lock().unwrap();
+
+        let _guard = self.counters.condvar.wait_while(guard, |_| !self.counters.is_idle()).unwrap();
+    }
+
+    /// Execute a function/closure using a worker from the thread pool and
+    /// return a `Receiver` that yields its result.
+    ///
+    /// The returned channel is a one-slot `bounded(1)` channel: the worker
+    /// sends its result after `f` returns without waiting for the caller,
+    /// and the caller retrieves it with `recv()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use threaded::ThreadPool;
+    ///
+    /// let tp = ThreadPool::new(1);
+    /// let result = tp.execute_result(|| 2 + 2);
+    /// assert_eq!(result.recv().unwrap(), 4);
+    /// ```
+    pub fn execute_result<F, T>(&self, f: F) -> Receiver<T>
+        where
+            F: FnOnce() -> T + Send + 'static,
+            T: Send + 'static,
+    {
+        let (result_sender, result_receiver) = bounded(1);
+
+        self.execute(move || {
+            let result = f();
+            let _ = result_sender.send(result);
+        });
+
+        result_receiver
+    }
+
+    /// Run `f` exactly once on every worker in the pool, e.g. to seed
+    /// thread-local state.
+    ///
+    /// Because workers share a single MPMC channel, a job cannot be aimed at
+    /// a specific worker. Instead `broadcast` submits `self.capacity()` jobs
+    /// that each call `f` and then wait on a shared `Barrier`; the barrier
+    /// keeps a fast worker from dequeuing a second broadcast job before every
+    /// other worker has dequeued its first. Each of those jobs is sent with a
+    /// guaranteed-delivery (blocking) send regardless of the pool's
+    /// `OverflowPolicy`, since a job dropped under `OverflowPolicy::DropNewest`
+    /// would leave the barrier permanently short of a waiter.
+    ///
+    /// # Deadlocks
+    ///
+    /// This blocks until every worker has passed the barrier, so it will
+    /// deadlock if fewer than `self.capacity()` workers are able to pick up a
+    /// job, for example while a `resize` shrink is retiring workers or while
+    /// the pool is otherwise short a live thread.
+    pub fn broadcast<F>(&self, f: F)
+        where
+            F: Fn() + Send + Sync + 'static,
+    {
+        let f = Arc::new(f);
+        let capacity = self.capacity();
+        let barrier = Arc::new(Barrier::new(capacity));
+
+        for _ in 0..capacity {
+            let f = f.clone();
+            let barrier = barrier.clone();
+
+            self.send_job(Box::new(move || {
+                f();
+                barrier.wait();
+            }));
+        }
+    }
+
+    /// Like [`ThreadPool::broadcast`], but collects each worker's return
+    /// value into a `Vec` once every worker has passed the barrier.
+    ///
+    /// See [`ThreadPool::broadcast`] for the deadlock caveat and the
+    /// guaranteed-delivery send it relies on.
+    pub fn broadcast_result<F, T>(&self, f: F) -> Vec<T>
+        where
+            F: Fn() -> T + Send + Sync + 'static,
+            T: Send + 'static,
+    {
+        let f = Arc::new(f);
+        let capacity = self.capacity();
+        let barrier = Arc::new(Barrier::new(capacity));
+        let (result_sender, result_receiver) = bounded(capacity);
+
+        for _ in 0..capacity {
+            let f = f.clone();
+            let barrier = barrier.clone();
+            let result_sender = result_sender.clone();
+
+            self.send_job(Box::new(move || {
+                let result = f();
+                barrier.wait();
+                let _ = result_sender.send(result);
+            }));
+        }
+
+        drop(result_sender);
+        result_receiver.iter().collect()
+    }
+
+    /// Send a job with a guaranteed-delivery (blocking) enqueue, bypassing
+    /// the pool's `OverflowPolicy`.
+    ///
+    /// Used where a dropped job would break an invariant that other code
+    /// relies on, e.g. `broadcast`'s one-job-per-worker barrier.
+    fn send_job(&self, job: Job) {
+        self.counters.job_queued();
         self.sender.send(Message::NewJob(job)).unwrap();
     }
 }
 
 impl Drop for ThreadPool {
     fn drop(&mut self) {
+        let mut workers = self.workers.lock().unwrap();
+
         // sending terminate to all workers
-        for _ in &self.workers {
+        for _ in workers.iter() {
             self.sender.send(Message::Terminate).unwrap();
         }
 
-        // joining worker threads
-        for worker in &mut self.workers {
-            if let Some(thread) = worker.thread.take() {
-                thread.join().unwrap();
-            }
+        // take every handle before joining, so the registry lock is free
+        // for a concurrently-panicking job's Sentinel to respawn into
+        let handles: Vec<_> = workers.iter_mut().filter_map(|worker| worker.thread.take()).collect();
+        drop(workers);
+
+        // joining worker threads; a worker whose job panicked reports an
+        // `Err` here rather than propagating the panic a second time
+        for thread in handles {
+            let _ = thread.join();
         }
     }
 }
 
+static DEFAULT_POOL: OnceLock<ThreadPool> = OnceLock::new();
+
+/// Names of the environment variables consulted for the [`default_pool`]'s
+/// capacity, in priority order.
+const CAPACITY_ENV_VARS: [&str; 2] = ["THREADED", "THREADPOOL"];
+
+fn default_capacity() -> usize {
+    CAPACITY_ENV_VARS.iter()
+        .find_map(|name| std::env::var(name).ok())
+        .and_then(|value| value.parse::<usize>().ok())
+        .filter(|&capacity| capacity > 0)
+        .unwrap_or_else(|| thread::available_parallelism().map(|n| n.get()).unwrap_or(1))
+}
+
+/// Process-wide default thread pool.
+///
+/// Sized on first use from the `THREADED` (or `THREADPOOL`) environment
+/// variable, parsed as a `usize`, falling back to the number of logical CPUs
+/// when the variable is unset or unparseable. Lets small prototypes submit
+/// parallel work without threading a `ThreadPool` handle through the call
+/// stack.
+pub fn default_pool() -> &'static ThreadPool {
+    DEFAULT_POOL.get_or_init(|| ThreadPool::new(default_capacity()))
+}
+
+/// Submit a job to the process-wide [`default_pool`].
+///
+/// # Examples
+///
+/// ```
+/// threaded::execute(|| {
+///     // work running on the default pool
+/// });
+/// ```
+pub fn execute<F>(f: F)
+    where
+        F: FnOnce() + Send + 'static,
+{
+    default_pool().execute(f);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -177,6 +654,98 @@ mod tests {
         assert_eq!(executed.load(Ordering::SeqCst), true);
     }
 
+    #[test]
+    fn execute_result_yields_computed_value() {
+        let tp = ThreadPool::new(1);
+        let result = tp.execute_result(|| 2 + 2);
+        assert_eq!(result.recv().unwrap(), 4);
+    }
+
+    #[test]
+    fn join_waits_for_queued_and_active_jobs() {
+        use std::thread::sleep;
+        use std::time::Duration;
+
+        let tp = ThreadPool::new(2);
+        let executed = Arc::new(AtomicBool::new(false));
+        {
+            let executed = executed.clone();
+            tp.execute(move || {
+                sleep(Duration::from_millis(50));
+                executed.swap(true, Ordering::SeqCst);
+            });
+        }
+
+        tp.join();
+
+        assert_eq!(tp.active_count(), 0);
+        assert_eq!(tp.queued_count(), 0);
+        assert_eq!(executed.load(Ordering::SeqCst), true);
+    }
+
+    #[test]
+    fn broadcast_result_collects_one_value_per_worker() {
+        let capacity = 3;
+        let tp = ThreadPool::new(capacity);
+
+        let results = tp.broadcast_result(|| 1);
+
+        assert_eq!(results.len(), capacity);
+        assert_eq!(results.iter().sum::<i32>(), capacity as i32);
+    }
+
+    #[test]
+    fn broadcast_ignores_drop_newest_overflow_policy() {
+        let capacity = 4;
+        let tp = ThreadPoolBuilder::new()
+            .num_threads(capacity)
+            .queue_capacity(Some(1))
+            .overflow_policy(OverflowPolicy::DropNewest)
+            .build();
+
+        // a queue this small would drop 3 of the 4 jobs under DropNewest if
+        // broadcast went through execute()/try_execute(); every worker must
+        // still reach the barrier, or this test hangs
+        let results = tp.broadcast_result(|| 1);
+
+        assert_eq!(results.len(), capacity);
+        assert_eq!(results.iter().sum::<i32>(), capacity as i32);
+    }
+
+    #[test]
+    fn builder_applies_thread_name() {
+        let tp = ThreadPoolBuilder::new()
+            .num_threads(1)
+            .thread_name("threaded-worker".to_string())
+            .build();
+
+        let name = tp.execute_result(|| thread::current().name().map(|name| name.to_string()));
+        assert_eq!(name.recv().unwrap().as_deref(), Some("threaded-worker"));
+    }
+
+    #[test]
+    fn builder_drop_newest_discards_jobs_past_queue_capacity() {
+        let tp = ThreadPoolBuilder::new()
+            .num_threads(1)
+            .queue_capacity(Some(1))
+            .overflow_policy(OverflowPolicy::DropNewest)
+            .build();
+
+        // block the only worker so the next jobs pile up in the queue
+        let (release_sender, release_receiver) = bounded::<()>(0);
+        tp.execute(move || {
+            let _ = release_receiver.recv();
+        });
+        while tp.active_count() == 0 {
+            thread::yield_now();
+        }
+
+        assert!(tp.try_execute(|| ()).is_ok());
+        assert!(tp.try_execute(|| ()).is_err());
+
+        let _ = release_sender.send(());
+    }
+
     #[test]
     fn executes_spmc_jobs() {
         // fixme - verify jobs run in parallel (worker id, overlap, etc)
@@ -209,12 +778,60 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
+    fn worker_replaced_after_panicking_job() {
+        let tp = ThreadPool::new(1);
+
+        tp.execute(move || {
+            panic!("job panicked on purpose");
+        });
+
+        // on a single-worker pool, only a respawned worker can pick this up,
+        // so waiting for its result deterministically proves the sentinel
+        // noticed the unwind and replaced the dead worker
+        tp.execute_result(|| ()).recv().unwrap();
+
+        let executed = Arc::new(AtomicBool::new(false));
+        {
+            let executed = executed.clone();
+            tp.execute(move || {
+                executed.swap(true, Ordering::SeqCst);
+            });
+        }
+
+        // the sentinel swaps the replacement worker back into the shared
+        // registry in place, so capacity and Drop's join guarantee hold
+        assert_eq!(tp.capacity(), 1);
+        drop(tp);
+
+        assert_eq!(executed.load(Ordering::SeqCst), true);
+    }
+
+    #[test]
+    fn resize_after_panic_matches_the_replacement_workers_id() {
+        let mut tp = ThreadPool::new(1);
+
+        tp.execute(move || {
+            panic!("job panicked on purpose");
+        });
+
+        // on a single-worker pool, only a respawned worker can pick this up,
+        // so waiting for its result deterministically proves the sentinel
+        // swapped its replacement into the registry before resize runs
+        tp.execute_result(|| ()).recv().unwrap();
+
+        tp.resize(2);
+        assert_eq!(tp.capacity(), 2);
+
+        tp.resize(1);
+        assert_eq!(tp.capacity(), 1);
+    }
+
+    #[test]
     fn thread_pool_resize_to_bigger_capacity() {
         let capacity = 2;
         let resize_capacity = 4;
-        
-        let tp = ThreadPool::new(capacity);
+
+        let mut tp = ThreadPool::new(capacity);
         assert_eq!(tp.capacity(), capacity);
 
         tp.resize(resize_capacity);
@@ -222,15 +839,32 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
     fn thread_pool_resize_to_smaller_capacity() {
         let capacity = 4;
         let resize_capacity = 2;
 
-        let tp = ThreadPool::new(capacity);
+        let mut tp = ThreadPool::new(capacity);
         assert_eq!(tp.capacity(), capacity);
 
         tp.resize(resize_capacity);
         assert_eq!(tp.capacity(), resize_capacity);
     }
+
+    #[test]
+    fn default_pool_is_sized_and_reused() {
+        let first = default_pool() as *const ThreadPool;
+        let second = default_pool() as *const ThreadPool;
+
+        assert_eq!(first, second);
+        assert!(default_pool().capacity() > 0);
+    }
+
+    #[test]
+    fn execute_submits_to_default_pool() {
+        let (result_sender, result_receiver) = bounded(1);
+        execute(move || {
+            let _ = result_sender.send(2 + 2);
+        });
+        assert_eq!(result_receiver.recv().unwrap(), 4);
+    }
 }